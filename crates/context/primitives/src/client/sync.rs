@@ -105,24 +105,26 @@ impl ContextClient {
                 let metadata = application.metadata;
 
                 let derived_application_id = {
-                    let app_id = match source.scheme() {
+                    // the source is installed as published, since the
+                    // application id has to be reproduced from it
+                    let blob = match source.scheme() {
                         "http" | "https" => self
                             .node_client
-                            .install_application_from_url(source.clone(), metadata.clone(), None)
+                            .add_blob_from_url(source.clone(), None)
                             .await
                             .ok(),
                         _ => None,
                     };
 
-                    match app_id {
-                        Some(id) => id,
-                        None => self.node_client.install_application(
-                            &application.blob.bytecode,
-                            application.size,
-                            &source.into(),
-                            metadata,
-                        )?,
-                    }
+                    let (blob_id, size) =
+                        blob.unwrap_or((application.blob.bytecode, application.size));
+
+                    self.node_client.install_application(
+                        &blob_id,
+                        size,
+                        &source.into(),
+                        metadata,
+                    )?
                 };
 
                 if application.id != derived_application_id {
@@ -22,7 +22,11 @@ pub struct InstallCommand {
     #[clap(long, short, conflicts_with = "path", help = "Url of the application")]
     pub url: Option<String>,
 
-    #[clap(short, long, help = "Metadata for the application")]
+    #[clap(
+        short,
+        long,
+        help = "Metadata for the application, as a JSON document with at least `name` and `version`"
+    )]
     pub metadata: Option<String>,
 
     #[clap(long, help = "Hash of the application")]
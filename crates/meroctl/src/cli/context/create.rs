@@ -47,7 +47,7 @@ pub struct CreateCommand {
 
     #[clap(
         requires = "watch",
-        help = "Metadata needed for the application installation"
+        help = "Metadata for the application, as a JSON document with at least `name` and `version`"
     )]
     pub metadata: Option<String>,
 
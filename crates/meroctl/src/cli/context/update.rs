@@ -44,7 +44,7 @@ pub struct UpdateCommand {
     #[clap(
         long,
         conflicts_with = "application_id",
-        help = "Metadata needed for the application installation"
+        help = "Metadata for the application, as a JSON document with at least `name` and `version`"
     )]
     metadata: Option<String>,
 
@@ -68,17 +68,22 @@ impl Report for ListApplicationsResponse {
 
             let _ = table.set_header(vec![
                 Cell::new("ID").fg(Color::Blue),
+                Cell::new("Name").fg(Color::Blue),
+                Cell::new("Version").fg(Color::Blue),
                 Cell::new("Source").fg(Color::Blue),
                 Cell::new("Size").fg(Color::Blue),
                 Cell::new("Blob").fg(Color::Blue),
             ]);
 
-            for app in &self.data.apps {
+            for listed in &self.data.apps {
+                let app = &listed.application;
                 let _ = table.add_row(vec![
-                    &app.id.to_string(),
-                    &app.source.to_string(),
-                    &app.size.to_string(),
-                    &format!("Blob: {}", app.blob.bytecode),
+                    app.id.to_string(),
+                    listed.name.as_deref().unwrap_or("-").to_owned(),
+                    listed.version.as_deref().unwrap_or("-").to_owned(),
+                    app.source.to_string(),
+                    app.size.to_string(),
+                    format!("Blob: {}", app.blob.bytecode),
                 ]);
             }
 
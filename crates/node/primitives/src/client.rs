@@ -26,6 +26,11 @@ mod alias;
 mod application;
 mod blob;
 
+pub use application::{
+    sanitize_source, ApplicationMetadata, ApplicationsPage, InvalidApplicationMetadata,
+    InvalidApplicationSource,
+};
+
 #[derive(Clone, Debug)]
 pub struct NodeClient {
     datastore: Store,
@@ -88,10 +88,44 @@ impl UninstallApplicationResponse {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListedApplication {
+    #[serde(flatten)]
+    pub application: Application,
+    pub name: Option<String>,
+    pub version: Option<String>,
+    /// Whether `source` is a placeholder, the stored one being unfit for display.
+    #[serde(default)]
+    pub source_redacted: bool,
+}
+
+impl ListedApplication {
+    pub const fn new(
+        application: Application,
+        name: Option<String>,
+        version: Option<String>,
+        source_redacted: bool,
+    ) -> Self {
+        Self {
+            application,
+            name,
+            version,
+            source_redacted,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListApplicationResponseData {
-    pub apps: Vec<Application>,
+    pub apps: Vec<ListedApplication>,
+    /// Number of applications matching the query, across all pages.
+    #[serde(default)]
+    pub total: usize,
+    /// Number of unreadable applications left out of the listing.
+    #[serde(default)]
+    pub skipped: usize,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -101,9 +135,13 @@ pub struct ListApplicationsResponse {
 }
 
 impl ListApplicationsResponse {
-    pub const fn new(apps: Vec<Application>) -> Self {
+    pub const fn new(apps: Vec<ListedApplication>, total: usize, skipped: usize) -> Self {
         Self {
-            data: ListApplicationResponseData { apps },
+            data: ListApplicationResponseData {
+                apps,
+                total,
+                skipped,
+            },
         }
     }
 }
@@ -112,6 +150,10 @@ impl ListApplicationsResponse {
 #[serde(rename_all = "camelCase")]
 pub struct GetApplicationResponseData {
     pub application: Option<Application>,
+    /// Whether the application's source is a placeholder, the stored one
+    /// being unfit for display.
+    #[serde(default)]
+    pub source_redacted: bool,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -121,9 +163,12 @@ pub struct GetApplicationResponse {
 }
 
 impl GetApplicationResponse {
-    pub const fn new(application: Option<Application>) -> Self {
+    pub const fn new(application: Option<Application>, source_redacted: bool) -> Self {
         Self {
-            data: GetApplicationResponseData { application },
+            data: GetApplicationResponseData {
+                application,
+                source_redacted,
+            },
         }
     }
 }
@@ -3,3 +3,24 @@ pub mod install_application;
 pub mod install_dev_application;
 pub mod list_applications;
 pub mod uninstall_application;
+
+use calimero_node_primitives::client::sanitize_source;
+use calimero_primitives::application::Application;
+
+/// Placeholder for sources that can't be shown even once sanitized.
+const REDACTED_SOURCE: &str = "redacted:";
+
+/// Sanitizes an application's source for display.
+///
+/// Returns whether the source had to be replaced by a placeholder because
+/// it couldn't be sanitized.
+fn redact_source(application: &mut Application) -> bool {
+    if let Ok(source) = sanitize_source(&application.source) {
+        application.source = source;
+        return false;
+    }
+
+    application.source = REDACTED_SOURCE.parse().expect("placeholder is a valid url");
+
+    true
+}
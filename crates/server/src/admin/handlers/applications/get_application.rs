@@ -7,6 +7,7 @@ use axum::Extension;
 use calimero_primitives::application::ApplicationId;
 use calimero_server_primitives::admin::GetApplicationResponse;
 
+use super::redact_source;
 use crate::admin::service::ApiResponse;
 use crate::AdminState;
 
@@ -15,10 +16,14 @@ pub async fn handler(
     Path(application_id): Path<ApplicationId>,
 ) -> impl IntoResponse {
     match state.node_client.get_application(&application_id) {
-        Ok(application) => ApiResponse {
-            payload: GetApplicationResponse::new(application),
+        Ok(mut application) => {
+            let source_redacted = application.as_mut().is_some_and(redact_source);
+
+            ApiResponse {
+                payload: GetApplicationResponse::new(application, source_redacted),
+            }
+            .into_response()
         }
-        .into_response(),
         Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
     }
 }
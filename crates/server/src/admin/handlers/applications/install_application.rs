@@ -1,17 +1,21 @@
 use std::sync::Arc;
 
-use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::{Extension, Json};
+use calimero_node_primitives::client::ApplicationMetadata;
 use calimero_server_primitives::admin::{InstallApplicationRequest, InstallApplicationResponse};
 
-use crate::admin::service::ApiResponse;
+use crate::admin::service::{parse_application_error, ApiResponse};
 use crate::AdminState;
 
 pub async fn handler(
     Extension(state): Extension<Arc<AdminState>>,
     Json(req): Json<InstallApplicationRequest>,
 ) -> impl IntoResponse {
+    if let Err(err) = ApplicationMetadata::check(&req.metadata) {
+        return parse_application_error(err.into()).into_response();
+    }
+
     match state
         .node_client
         .install_application_from_url(req.url, req.metadata, req.hash.as_ref())
@@ -21,6 +25,6 @@ pub async fn handler(
             payload: InstallApplicationResponse::new(application_id),
         }
         .into_response(),
-        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+        Err(err) => parse_application_error(err).into_response(),
     }
 }
@@ -1,24 +1,65 @@
 use std::sync::Arc;
 
+use axum::extract::Query;
 use axum::response::IntoResponse;
 use axum::Extension;
-use calimero_server_primitives::admin::ListApplicationsResponse;
+use calimero_server_primitives::admin::{ListApplicationsResponse, ListedApplication};
+use serde::Deserialize;
 
+use super::redact_source;
 use crate::admin::service::{parse_api_error, ApiResponse};
 use crate::AdminState;
 
-pub async fn handler(Extension(state): Extension<Arc<AdminState>>) -> impl IntoResponse {
-    let applications = state
+#[derive(Debug, Deserialize)]
+pub struct ListApplicationsQuery {
+    /// Number of applications to skip
+    #[serde(default)]
+    offset: usize,
+    /// Maximum number of applications to return, all of them if unset
+    limit: Option<usize>,
+    /// Only list applications whose source uses this scheme
+    scheme: Option<String>,
+}
+
+/// List installed applications
+///
+/// Query parameters:
+/// - `offset`: Number of applications to skip (optional)
+/// - `limit`: Maximum number of applications to return (optional)
+/// - `scheme`: Only list applications whose source uses this scheme (optional)
+pub async fn handler(
+    Query(query): Query<ListApplicationsQuery>,
+    Extension(state): Extension<Arc<AdminState>>,
+) -> impl IntoResponse {
+    let page = state
         .node_client
-        .list_applications()
+        .list_applications_paginated(
+            query.offset,
+            query.limit.unwrap_or(usize::MAX),
+            query.scheme.as_deref(),
+        )
         .map_err(|err| parse_api_error(err).into_response());
-    match applications {
-        Ok(applications) => {
+    match page {
+        Ok(page) => {
+            let applications = page
+                .applications
+                .into_iter()
+                .map(|(mut application, metadata)| {
+                    let source_redacted = redact_source(&mut application);
+
+                    let (name, version) = metadata.map_or((None, None), |metadata| {
+                        (Some(metadata.name), Some(metadata.version))
+                    });
+
+                    ListedApplication::new(application, name, version, source_redacted)
+                })
+                .collect();
+
             ApiResponse {
-                payload: ListApplicationsResponse::new(applications),
+                payload: ListApplicationsResponse::new(applications, page.total, page.skipped),
             }
+            .into_response()
         }
-        .into_response(),
         Err(err) => err.into_response(),
     }
 }
@@ -9,6 +9,7 @@ use axum::http::{header, HeaderMap, HeaderValue, Response, StatusCode, Uri};
 use axum::response::IntoResponse;
 use axum::routing::{get, post, put};
 use axum::{Extension, Router};
+use calimero_node_primitives::client::{InvalidApplicationMetadata, InvalidApplicationSource};
 use eyre::Report;
 use rust_embed::{EmbeddedFile, RustEmbed};
 use serde::{Deserialize, Serialize};
@@ -425,6 +426,22 @@ pub fn parse_api_error(err: Report) -> ApiError {
     }
 }
 
+/// Like [`parse_api_error`], but reports invalid application sources and
+/// metadata as client errors.
+#[must_use]
+pub fn parse_application_error(err: Report) -> ApiError {
+    if err.downcast_ref::<InvalidApplicationMetadata>().is_some()
+        || err.downcast_ref::<InvalidApplicationSource>().is_some()
+    {
+        return ApiError {
+            status_code: StatusCode::BAD_REQUEST,
+            message: err.to_string(),
+        };
+    }
+
+    parse_api_error(err)
+}
+
 #[derive(Debug, Serialize)]
 struct GetHealthResponse {
     data: HealthStatus,